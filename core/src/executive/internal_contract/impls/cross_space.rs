@@ -35,11 +35,140 @@ use primitives::{
     Action, LogEntry,
 };
 use solidity_abi::{ABIDecodable, ABIEncodable};
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+
+/// EIP-2929 cold account access cost: the gas charged the first time an
+/// address is touched within a transaction.
+const COLD_ACCOUNT_ACCESS_COST: usize = 2600;
+/// EIP-2929 cold storage access cost: the gas charged the first time a
+/// storage key is touched within a transaction.
+const COLD_SLOAD_COST: usize = 2100;
+/// EIP-2929 warm storage read cost: the gas charged for every access to an
+/// address or storage key that has already been touched in the transaction.
+const WARM_STORAGE_READ_COST: usize = 100;
+/// EIP-2930 access list entry cost: charged up front for each address
+/// listed in an access list, in exchange for pre-warming it.
+const ACCESS_LIST_ADDRESS_COST: usize = 2400;
+/// EIP-2930 access list entry cost: charged up front for each storage key
+/// listed in an access list, in exchange for pre-warming it.
+const ACCESS_LIST_STORAGE_KEY_COST: usize = 1900;
+
+/// One entry of an EIP-2930-style access list: an address together with
+/// the storage keys of that address to pre-warm.
+pub type AccessListItem = (Address, Vec<H256>);
+
+fn charge_access_list(
+    access_list: &[AccessListItem], gas_left: U256,
+    context: &mut InternalRefContext,
+) -> Result<U256, vm::Error> {
+    let mut cost = U256::zero();
+    for (_, keys) in access_list {
+        cost += U256::from(ACCESS_LIST_ADDRESS_COST)
+            + U256::from(ACCESS_LIST_STORAGE_KEY_COST) * keys.len();
+    }
+    if gas_left < cost {
+        return Err(vm::Error::OutOfGas);
+    }
+    for (address, keys) in access_list {
+        context.access_list.warm_address(address.with_evm_space());
+        for key in keys {
+            context.access_list.warm_storage_key(*address, *key);
+        }
+    }
+    Ok(gas_left - cost)
+}
+
+/// Per-transaction warm/cold access list for the EVM-space accounts and
+/// storage slots touched through the cross-space bridge, mirroring
+/// EIP-2929. Entries are journaled so that a reverted sub-call can undo the
+/// accesses it recorded without disturbing ones recorded before it.
+#[derive(Default)]
+pub struct AccessList {
+    accessed_addresses: HashSet<AddressWithSpace>,
+    accessed_storage_keys: HashSet<(Address, H256)>,
+    checkpoints: Vec<(Vec<AddressWithSpace>, Vec<(Address, H256)>)>,
+}
+
+impl AccessList {
+    /// Marks `address` as accessed without charging for it, e.g. to
+    /// pre-warm the mapped sender/origin at transaction entry or to honor
+    /// an EIP-2930 access list.
+    pub fn warm_address(&mut self, address: AddressWithSpace) {
+        if self.accessed_addresses.insert(address) {
+            if let Some(top) = self.checkpoints.last_mut() {
+                top.0.push(address);
+            }
+        }
+    }
+
+    /// Marks `(address, key)` as accessed without charging for it.
+    pub fn warm_storage_key(&mut self, address: Address, key: H256) {
+        if self.accessed_storage_keys.insert((address, key)) {
+            if let Some(top) = self.checkpoints.last_mut() {
+                top.1.push((address, key));
+            }
+        }
+    }
+
+    /// Charges the EIP-2929 cold/warm cost for accessing `address`, warming
+    /// it for subsequent accesses in the same transaction.
+    pub fn access_address(&mut self, address: AddressWithSpace) -> U256 {
+        if self.accessed_addresses.contains(&address) {
+            U256::from(WARM_STORAGE_READ_COST)
+        } else {
+            self.warm_address(address);
+            U256::from(COLD_ACCOUNT_ACCESS_COST)
+        }
+    }
+
+    /// Charges the EIP-2929 cold/warm cost for accessing `(address, key)`.
+    pub fn access_storage_key(&mut self, address: Address, key: H256) -> U256 {
+        if self.accessed_storage_keys.contains(&(address, key)) {
+            U256::from(WARM_STORAGE_READ_COST)
+        } else {
+            self.warm_storage_key(address, key);
+            U256::from(COLD_SLOAD_COST)
+        }
+    }
+
+    /// Opens a new journal frame. Entries inserted after this call can be
+    /// undone with `revert_to_checkpoint` without disturbing entries
+    /// recorded in an enclosing frame.
+    pub fn checkpoint(&mut self) {
+        self.checkpoints.push((Vec::new(), Vec::new()));
+    }
+
+    /// Keeps the entries recorded since the last `checkpoint`, folding them
+    /// into the enclosing frame (or making them permanent if there is
+    /// none).
+    pub fn discard_checkpoint(&mut self) {
+        if let Some((addresses, keys)) = self.checkpoints.pop() {
+            if let Some(parent) = self.checkpoints.last_mut() {
+                parent.0.extend(addresses);
+                parent.1.extend(keys);
+            }
+        }
+    }
+
+    /// Undoes every entry inserted since the last `checkpoint`.
+    pub fn revert_to_checkpoint(&mut self) {
+        if let Some((addresses, keys)) = self.checkpoints.pop() {
+            for address in addresses {
+                self.accessed_addresses.remove(&address);
+            }
+            for key in keys {
+                self.accessed_storage_keys.remove(&key);
+            }
+        }
+    }
+}
 
 pub fn create_gas(
-    context: &InternalRefContext, code_length: usize, hash_length: usize,
+    params: &ActionParams, context: &mut InternalRefContext,
+    code_length: usize, hash_length: usize,
 ) -> DbResult<U256> {
+    warm_transaction_entry(params.sender, params.original_sender, context);
+
     let base_gas = U256::from(context.spec.create_gas);
     let hash_words = (hash_length + 31) / 32;
 
@@ -47,6 +176,8 @@ pub fn create_gas(
         context.spec.sha3_gas + context.spec.sha3_word_gas * hash_words;
 
     let address_mapping_gas = context.spec.sha3_gas * 3;
+    let address_access_gas =
+        context.access_list.access_address(evm_map(params.sender));
 
     let log_data_length = H256::len_bytes() * 4 + code_length;
 
@@ -54,18 +185,24 @@ pub fn create_gas(
         + 3 * context.spec.log_topic_gas
         + context.spec.log_data_gas * log_data_length;
 
-    Ok(base_gas + keccak_code_gas + address_mapping_gas + log_gas)
+    Ok(base_gas
+        + keccak_code_gas
+        + address_mapping_gas
+        + address_access_gas
+        + log_gas)
 }
 
 pub fn call_gas(
-    receiver: Address, params: &ActionParams, context: &InternalRefContext,
+    receiver: Address, params: &ActionParams, context: &mut InternalRefContext,
     data_length: usize, is_static: bool,
 ) -> DbResult<U256>
 {
+    warm_transaction_entry(params.sender, params.original_sender, context);
+
+    let receiver = receiver.with_evm_space();
+
     let new_account_gas = if !is_static
-        && !context
-            .state
-            .exists_and_not_null(&receiver.with_evm_space())?
+        && !context.state.exists_and_not_null(&receiver)?
     {
         context.spec.call_new_account_gas * context.spec.evm_gas_ratio
     } else {
@@ -82,6 +219,7 @@ pub fn call_gas(
         U256::from(context.spec.call_gas) + new_account_gas + transfer_gas;
 
     let address_mapping_gas = context.spec.sha3_gas * 4;
+    let address_access_gas = context.access_list.access_address(receiver);
 
     let log_data_length = H256::len_bytes() * 4 + data_length;
 
@@ -93,13 +231,20 @@ pub fn call_gas(
         0
     };
 
-    Ok(call_gas + address_mapping_gas + log_gas)
+    Ok(call_gas + address_mapping_gas + address_access_gas + log_gas)
 }
 
 #[derive(Clone)]
 pub struct Resume {
     pub params: ActionParams,
     pub gas_retained: U256,
+    /// The gas forwarded to the EVM-space sub-execution (`next_params.gas`
+    /// at the point the trap was created), used to compute the trace's
+    /// `gas_used` once the sub-execution returns.
+    pub forwarded_gas: U256,
+    /// `Some(address)` when this `Resume` completes a create trap, so
+    /// `PassResult::exec` can emit a create trace instead of a call trace.
+    pub created_address: Option<Address>,
 }
 
 impl ResumeCreate for Resume {
@@ -178,7 +323,7 @@ pub struct PassResult {
 impl Exec for PassResult {
     fn exec(
         mut self: Box<Self>, context: &mut dyn Context,
-        _tracer: &mut dyn VmObserve,
+        tracer: &mut dyn VmObserve,
     ) -> ExecTrapResult<GasLeft>
     {
         let context = &mut context.internal_ref();
@@ -218,6 +363,33 @@ impl Exec for PassResult {
             }
         }
 
+        if self.apply_state {
+            context.access_list.discard_checkpoint();
+        } else {
+            context.access_list.revert_to_checkpoint();
+        }
+
+        let gas_used =
+            self.resume.forwarded_gas.saturating_sub(self.gas_left);
+        match (&self.return_data, self.resume.created_address) {
+            (Ok(output), Some(address)) => {
+                tracer.done_trace_create(
+                    gas_used,
+                    self.apply_state,
+                    &output.to_vec(),
+                    address,
+                );
+            }
+            (Ok(output), None) => {
+                tracer.done_trace_call(
+                    gas_used,
+                    self.apply_state,
+                    &output.to_vec(),
+                );
+            }
+            (Err(err), _) => tracer.done_trace_failed(err),
+        }
+
         let result = match self.return_data {
             Ok(data) => Ok(GasLeft::NeedsReturn {
                 gas_left: gas_returned,
@@ -234,6 +406,23 @@ pub fn evm_map(address: Address) -> AddressWithSpace {
     Address::from(keccak(&address)).with_evm_space()
 }
 
+/// Pre-warms the mapped EVM-space addresses of `sender` and
+/// `original_sender`, called from `create_gas`/`call_gas` before they charge
+/// for any address access, so within those two a caller's own (mapped)
+/// address and that of the ultimate transaction signer are always charged
+/// at the warm rate, much as EIP-2929 pre-warms `tx.origin` and `tx.to`.
+/// `withdraw_gas`, `mapped_balance_gas` and `mapped_nonce_gas` do not call
+/// this: the first of them to touch a mapped address before any
+/// `create_gas`/`call_gas` in the transaction pays the cold cost for it,
+/// even when it is the caller's own mapped address.
+pub fn warm_transaction_entry(
+    sender: Address, original_sender: Address,
+    context: &mut InternalRefContext,
+) {
+    context.access_list.warm_address(evm_map(sender));
+    context.access_list.warm_address(evm_map(original_sender));
+}
+
 pub fn process_trap<T>(
     result: Result<ExecTrap, vm::Error>, _phantom: PhantomData<T>,
 ) -> ExecTrapResult<T> {
@@ -243,22 +432,51 @@ pub fn process_trap<T>(
     }
 }
 
+/// Splits `gas_left` into the gas forwarded to the EVM-space sub-execution
+/// and the gas retained by the caller.
+///
+/// Before `Spec::cip_eip150_gas_forwarding`, Conflux always forwarded a
+/// fixed `1 / CROSS_SPACE_GAS_RATIO` share. Once the flag is active, cross-
+/// space calls instead follow the EIP-150 63/64 rule: the sub-call
+/// receives at most all-but-one-64th of what's left, capped further by an
+/// explicit `requested_gas` if the caller asked for less.
+fn forward_gas(
+    gas_left: U256, requested_gas: Option<U256>, spec: &Spec,
+) -> (U256, U256) {
+    if !spec.cip_eip150_gas_forwarding {
+        let forwarded = gas_left / CROSS_SPACE_GAS_RATIO;
+        return (forwarded, gas_left - forwarded);
+    }
+
+    let all_but_one_64th = gas_left - gas_left / 64;
+    let forwarded = match requested_gas {
+        Some(requested) if requested < all_but_one_64th => requested,
+        _ => all_but_one_64th,
+    };
+    (forwarded, gas_left - forwarded)
+}
+
 pub fn call_to_evmcore(
     receiver: Address, data: Vec<u8>, call_type: CallType,
-    params: &ActionParams, gas_left: U256, context: &mut InternalRefContext,
+    params: &ActionParams, gas_left: U256, requested_gas: Option<U256>,
+    access_list: Vec<AccessListItem>, context: &mut InternalRefContext,
+    tracer: &mut dyn VmObserve,
 ) -> Result<ExecTrap, vm::Error>
 {
     if context.depth >= context.spec.max_depth {
         return Err(vm::Error::InternalContract("Exceed Depth".into()));
     }
 
-    let call_gas = gas_left / CROSS_SPACE_GAS_RATIO
+    let gas_left = charge_access_list(&access_list, gas_left, context)?;
+
+    let (forwarded_gas, reserved_gas) =
+        forward_gas(gas_left, requested_gas, context.spec);
+    let call_gas = forwarded_gas
         + if params.value.value() > U256::zero() {
             U256::from(context.spec.call_stipend)
         } else {
             U256::zero()
         };
-    let reserved_gas = gas_left - gas_left / CROSS_SPACE_GAS_RATIO;
 
     let mapped_sender = evm_map(params.sender);
     let mapped_origin = evm_map(params.original_sender);
@@ -300,39 +518,48 @@ pub fn call_to_evmcore(
         .inc_nonce(&mapped_sender, &context.spec.account_start_nonce)?;
 
     if call_type == CallType::Call {
-        CallEvent::log(
-            &(mapped_sender.address.0, address.address.0),
-            &(params.value.value(), nonce, call_gas, data),
-            params,
-            context,
-        )?;
+        if context.spec.cip_eip2930_access_list {
+            CallEvent::log(
+                &(mapped_sender.address.0, address.address.0),
+                &(params.value.value(), nonce, call_gas, data, access_list),
+                params,
+                context,
+            )?;
+        } else {
+            CallEvent::log(
+                &(mapped_sender.address.0, address.address.0),
+                &(params.value.value(), nonce, call_gas, data),
+                params,
+                context,
+            )?;
+        }
     }
 
+    context.access_list.checkpoint();
+    tracer.prepare_trace_call(&next_params, context.depth, false);
+
     return Ok(ExecTrap::Call(
         next_params,
         Box::new(Resume {
             params: params.clone(),
             gas_retained: reserved_gas,
+            forwarded_gas: call_gas,
+            created_address: None,
         }),
     ));
 }
 
 pub fn create_to_evmcore(
     init: Vec<u8>, salt: Option<H256>, params: &ActionParams, gas_left: U256,
-    context: &mut InternalRefContext,
+    requested_gas: Option<U256>, access_list: Vec<AccessListItem>,
+    context: &mut InternalRefContext, tracer: &mut dyn VmObserve,
 ) -> Result<ExecTrap, vm::Error>
 {
     if context.depth >= context.spec.max_depth {
         return Err(vm::Error::InternalContract("Exceed Depth".into()));
     }
 
-    let call_gas = gas_left / CROSS_SPACE_GAS_RATIO
-        + if params.value.value() > U256::zero() {
-            U256::from(context.spec.call_stipend)
-        } else {
-            U256::zero()
-        };
-    let reserved_gas = gas_left - gas_left / CROSS_SPACE_GAS_RATIO;
+    let gas_left = charge_access_list(&access_list, gas_left, context)?;
 
     let mapped_sender = evm_map(params.sender);
     let mapped_origin = evm_map(params.original_sender);
@@ -361,6 +588,25 @@ pub fn create_to_evmcore(
     );
     let address = address_with_space.address;
 
+    // Charged against the pre-split `gas_left`, not the (potentially much
+    // smaller, once `cip_eip150_gas_forwarding` applies) reserved share, so
+    // this surcharge can't push an otherwise-affordable create over budget.
+    let address_access_gas =
+        context.access_list.access_address(address_with_space);
+    if gas_left < address_access_gas {
+        return Err(vm::Error::OutOfGas);
+    }
+    let gas_left = gas_left - address_access_gas;
+
+    let (forwarded_gas, reserved_gas) =
+        forward_gas(gas_left, requested_gas, context.spec);
+    let call_gas = forwarded_gas
+        + if params.value.value() > U256::zero() {
+            U256::from(context.spec.call_stipend)
+        } else {
+            U256::zero()
+        };
+
     let next_params = ActionParams {
         space: Space::Ethereum,
         code_address: address,
@@ -383,22 +629,42 @@ pub fn create_to_evmcore(
     context
         .state
         .inc_nonce(&mapped_sender, &context.spec.account_start_nonce)?;
-    CreateEvent::log(
-        &(mapped_sender.address.0, address.0),
-        &(params.value.value(), nonce, call_gas, init),
-        params,
-        context,
-    )?;
+    if context.spec.cip_eip2930_access_list {
+        CreateEvent::log(
+            &(mapped_sender.address.0, address.0),
+            &(params.value.value(), nonce, call_gas, init, access_list),
+            params,
+            context,
+        )?;
+    } else {
+        CreateEvent::log(
+            &(mapped_sender.address.0, address.0),
+            &(params.value.value(), nonce, call_gas, init),
+            params,
+            context,
+        )?;
+    }
+
+    context.access_list.checkpoint();
+    tracer.prepare_trace_create(&next_params);
 
     return Ok(ExecTrap::Create(
         next_params,
         Box::new(Resume {
             params: params.clone(),
             gas_retained: reserved_gas,
+            forwarded_gas: call_gas,
+            created_address: Some(address),
         }),
     ));
 }
 
+pub fn withdraw_gas(
+    sender: Address, context: &mut InternalRefContext,
+) -> DbResult<U256> {
+    Ok(context.access_list.access_address(evm_map(sender)))
+}
+
 pub fn withdraw_from_evmcore(
     sender: Address, value: U256, params: &ActionParams,
     context: &mut InternalRefContext,
@@ -432,16 +698,30 @@ pub fn withdraw_from_evmcore(
     Ok(())
 }
 
+pub fn mapped_balance_gas(
+    address: Address, context: &mut InternalRefContext,
+) -> DbResult<U256> {
+    Ok(context.access_list.access_address(evm_map(address)))
+}
+
 pub fn mapped_balance(
     address: Address, context: &mut InternalRefContext,
 ) -> vm::Result<U256> {
-    Ok(context.state.balance(&evm_map(address))?)
+    let mapped_address = evm_map(address);
+    Ok(context.state.balance(&mapped_address)?)
+}
+
+pub fn mapped_nonce_gas(
+    address: Address, context: &mut InternalRefContext,
+) -> DbResult<U256> {
+    Ok(context.access_list.access_address(evm_map(address)))
 }
 
 pub fn mapped_nonce(
     address: Address, context: &mut InternalRefContext,
 ) -> vm::Result<U256> {
-    Ok(context.state.nonce(&evm_map(address))?)
+    let mapped_address = evm_map(address);
+    Ok(context.state.nonce(&mapped_address)?)
 }
 
 #[derive(Default)]
@@ -452,6 +732,7 @@ pub struct PhantomTransaction {
     pub gas_limit: U256,
     pub value: U256,
     pub data: Vec<u8>,
+    pub access_list: Vec<AccessListItem>,
 
     pub gas_used: U256,
     pub log_bloom: Bloom,
@@ -479,34 +760,135 @@ impl PhantomTransaction {
 
 type Bytes20 = [u8; 20];
 
+/// Error recovering the phantom transactions of a cross-space transaction
+/// from its logs. A single corrupted or unexpected log entry (a future
+/// event-signature change, a database inconsistency, ...) surfaces as one
+/// of these variants instead of panicking, so a caller can log-and-skip the
+/// offending block rather than abort.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhantomDecodeError {
+    /// A cross-space log carried fewer topics than its event signature
+    /// requires.
+    TopicCountMismatch {
+        event_sig: H256,
+        expected: usize,
+        actual: usize,
+    },
+    /// The ABI-encoded log data could not be decoded into the shape its
+    /// event signature implies.
+    AbiDecode { event_sig: H256 },
+    /// A `Return` event was observed with no open `Call`/`Create` to close.
+    UnmatchedReturn,
+    /// A `Call`/`Create` event was observed before the prior one had been
+    /// closed by a matching `Return`.
+    UnclosedCallOrCreate { from: Address },
+}
+
+impl std::fmt::Display for PhantomDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PhantomDecodeError::TopicCountMismatch {
+                event_sig,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "cross-space log for event {:?} has {} topics, expected {}",
+                event_sig, actual, expected
+            ),
+            PhantomDecodeError::AbiDecode { event_sig } => write!(
+                f,
+                "failed to ABI-decode cross-space log data for event {:?}",
+                event_sig
+            ),
+            PhantomDecodeError::UnmatchedReturn => write!(
+                f,
+                "cross-space Return event with no matching open Call/Create"
+            ),
+            PhantomDecodeError::UnclosedCallOrCreate { from } => write!(
+                f,
+                "cross-space Call/Create event opened before the prior one \
+                 from {:?} was closed by a Return",
+                from
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PhantomDecodeError {}
+
+fn decode_topic_address(
+    log: &LogEntry, index: usize, event_sig: H256,
+) -> Result<Address, PhantomDecodeError> {
+    let topic =
+        log.topics
+            .get(index)
+            .ok_or(PhantomDecodeError::TopicCountMismatch {
+                event_sig,
+                expected: index + 1,
+                actual: log.topics.len(),
+            })?;
+    Bytes20::abi_decode(topic.as_ref())
+        .map(Address::from)
+        .map_err(|_| PhantomDecodeError::AbiDecode { event_sig })
+}
+
 pub fn recover_phantom(
     logs: &[LogEntry], spec: &Spec, gas_price: U256,
-) -> Vec<PhantomTransaction> {
+) -> Result<Vec<PhantomTransaction>, PhantomDecodeError> {
     let mut phantom_txs: Vec<PhantomTransaction> = Default::default();
     let mut maybe_working_tx: Option<PhantomTransaction> = None;
     for log in logs.iter() {
         if log.address == *CROSS_SPACE_CONTRACT_ADDRESS {
-            let event_sig = log.topics.first().unwrap();
+            let event_sig = *log.topics.first().ok_or(
+                PhantomDecodeError::TopicCountMismatch {
+                    event_sig: H256::zero(),
+                    expected: 1,
+                    actual: 0,
+                },
+            )?;
             match event_sig {
-                _ if event_sig == &CallEvent::EVENT_SIG
-                    || event_sig == &CreateEvent::EVENT_SIG =>
+                _ if event_sig == CallEvent::EVENT_SIG
+                    || event_sig == CreateEvent::EVENT_SIG =>
                 {
-                    assert!(maybe_working_tx.is_none());
-                    let (value, nonce, gas_limit, data): (
-                        U256,
-                        U256,
-                        U256,
-                        Vec<u8>,
-                    ) = ABIDecodable::abi_decode(&log.data).unwrap();
-
-                    let from = Address::from(
-                        Bytes20::abi_decode(&log.topics[1].as_ref()).unwrap(),
-                    );
-                    let to = Address::from(
-                        Bytes20::abi_decode(&log.topics[2].as_ref()).unwrap(),
-                    );
-
-                    let is_create = event_sig == &CreateEvent::EVENT_SIG;
+                    if let Some(working_tx) = &maybe_working_tx {
+                        return Err(PhantomDecodeError::UnclosedCallOrCreate {
+                            from: working_tx.from,
+                        });
+                    }
+                    // Before `cip_eip2930_access_list` activates, `Call`/
+                    // `Create` events are a 4-tuple with no access list;
+                    // decode each log according to the spec that was active
+                    // when it was emitted so historical blocks keep
+                    // replaying correctly.
+                    let (value, nonce, gas_limit, data, access_list) =
+                        if spec.cip_eip2930_access_list {
+                            let decoded: (
+                                U256,
+                                U256,
+                                U256,
+                                Vec<u8>,
+                                Vec<AccessListItem>,
+                            ) = ABIDecodable::abi_decode(&log.data).map_err(
+                                |_| PhantomDecodeError::AbiDecode { event_sig },
+                            )?;
+                            decoded
+                        } else {
+                            let (value, nonce, gas_limit, data): (
+                                U256,
+                                U256,
+                                U256,
+                                Vec<u8>,
+                            ) = ABIDecodable::abi_decode(&log.data).map_err(
+                                |_| PhantomDecodeError::AbiDecode { event_sig },
+                            )?;
+                            (value, nonce, gas_limit, data, Vec::new())
+                        };
+
+                    let from = decode_topic_address(log, 1, event_sig)?;
+                    let to = decode_topic_address(log, 2, event_sig)?;
+
+                    let is_create = event_sig == CreateEvent::EVENT_SIG;
                     let gas_limit: U256 =
                         gas_limit + gas_required_for(is_create, &data, spec);
                     let action = if is_create {
@@ -528,15 +910,16 @@ pub fn recover_phantom(
                         value,
                         gas_limit,
                         data,
+                        access_list,
                         ..Default::default()
                     });
                 }
-                _ if event_sig == &WithdrawEvent::EVENT_SIG => {
-                    let from = Address::from(
-                        Bytes20::abi_decode(&log.topics[1].as_ref()).unwrap(),
-                    );
-                    let (value, nonce) =
-                        ABIDecodable::abi_decode(&log.data).unwrap();
+                _ if event_sig == WithdrawEvent::EVENT_SIG => {
+                    let from = decode_topic_address(log, 1, event_sig)?;
+                    let (value, nonce) = ABIDecodable::abi_decode(&log.data)
+                        .map_err(|_| PhantomDecodeError::AbiDecode {
+                            event_sig,
+                        })?;
                     phantom_txs.push(PhantomTransaction::simple_transfer(
                         from,
                         Address::zero(),
@@ -545,12 +928,14 @@ pub fn recover_phantom(
                         spec,
                     ));
                 }
-                _ if event_sig == &ReturnEvent::EVENT_SIG => {
+                _ if event_sig == ReturnEvent::EVENT_SIG => {
                     let (nonce, gas_left, success): (U256, U256, bool) =
-                        ABIDecodable::abi_decode(&log.data).unwrap();
+                        ABIDecodable::abi_decode(&log.data).map_err(
+                            |_| PhantomDecodeError::AbiDecode { event_sig },
+                        )?;
 
-                    let mut working_tx =
-                        std::mem::take(&mut maybe_working_tx).unwrap();
+                    let mut working_tx = std::mem::take(&mut maybe_working_tx)
+                        .ok_or(PhantomDecodeError::UnmatchedReturn)?;
                     working_tx.gas_used = working_tx.gas_limit - gas_left;
                     working_tx.outcome_status = if success {
                         TRANSACTION_OUTCOME_SUCCESS
@@ -582,5 +967,5 @@ pub fn recover_phantom(
             }
         }
     }
-    return phantom_txs;
+    Ok(phantom_txs)
 }